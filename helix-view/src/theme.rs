@@ -1,5 +1,6 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    fmt,
     path::{Path, PathBuf},
     str,
 };
@@ -35,6 +36,24 @@ pub static BASE16_DEFAULT_THEME: Lazy<Theme> = Lazy::new(|| Theme {
     ..Theme::from(BASE16_DEFAULT_THEME_DATA.clone())
 });
 
+/// Which terminal background a theme (or one of its declared
+/// `[variants.light]` / `[variants.dark]` tables) is designed for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Appearance {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Appearance {
+    fn variant_key(self) -> &'static str {
+        match self {
+            Appearance::Dark => "dark",
+            Appearance::Light => "light",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Loader {
     user_dir: PathBuf,
@@ -69,6 +88,18 @@ impl Loader {
 
     /// Load a theme first looking in the `user_dir` then in `default_dir`
     pub fn load(&self, name: &str) -> Result<Theme> {
+        self.load_with_appearance(name, Appearance::Dark)
+    }
+
+    /// Load a theme first looking in the `user_dir` then in `default_dir`. If
+    /// the theme declares `[variants.light]` / `[variants.dark]` tables, the
+    /// table matching `appearance` is merged on top of the shared base
+    /// (palette and styles common to both variants) via the same
+    /// [`FlavorLoader::merge_flavors`] strategy used for `inherits`. With
+    /// [`Appearance::Light`] the resulting theme is named `name:light`, whether
+    /// or not the theme declares variants. The built-in `default` and
+    /// `base16_default` themes are returned as is for either appearance.
+    pub fn load_with_appearance(&self, name: &str, appearance: Appearance) -> Result<Theme> {
         if name == "default" {
             return Ok(self.default());
         }
@@ -76,13 +107,39 @@ impl Loader {
             return Ok(self.base16_default());
         }
 
-        let theme = self.load_flavor(name, name, false).map(Theme::from)?;
+        let theme_toml = self.load_flavor(name, name, false)?;
+        let theme_toml = self.select_variant(theme_toml, appearance);
+        let theme = Theme::from(theme_toml);
+
+        let name = match appearance {
+            Appearance::Dark => name.to_string(),
+            Appearance::Light => format!("{name}:light"),
+        };
 
         Ok(Theme {
-            name: name.into(),
+            name,
+            appearance,
             ..theme
         })
     }
+
+    /// If `theme_toml` declares a `[variants]` table, merges the table
+    /// matching `appearance` on top of the remaining shared base and returns
+    /// the result; otherwise returns `theme_toml` unchanged.
+    fn select_variant(&self, mut theme_toml: Value, appearance: Appearance) -> Value {
+        let Some(base) = theme_toml.as_table_mut() else {
+            return theme_toml;
+        };
+
+        let Some(Value::Table(mut variants)) = base.remove("variants") else {
+            return theme_toml;
+        };
+
+        match variants.remove(appearance.variant_key()) {
+            Some(variant) => self.merge_flavors(theme_toml, variant),
+            None => theme_toml,
+        }
+    }
 }
 
 impl FlavorLoader<Theme> for Loader {
@@ -136,23 +193,26 @@ impl FlavorLoader<Theme> for Loader {
 #[derive(Clone, Debug, Default)]
 pub struct Theme {
     name: String,
+    appearance: Appearance,
 
     // UI styles are stored in a HashMap
     styles: HashMap<String, Style>,
     // tree-sitter highlight styles are stored in a Vec to optimize lookups
     scopes: Vec<String>,
     highlights: Vec<Style>,
+    diagnostics: Vec<ThemeDiagnostic>,
 }
 
 impl From<Value> for Theme {
     fn from(value: Value) -> Self {
         if let Value::Table(table) = value {
-            let (styles, scopes, highlights) = build_theme_values(table);
+            let (styles, scopes, highlights, diagnostics) = build_theme_values(table);
 
             Self {
                 styles,
                 scopes,
                 highlights,
+                diagnostics,
                 ..Default::default()
             }
         } else {
@@ -169,52 +229,187 @@ impl<'de> Deserialize<'de> for Theme {
     {
         let values = Map::<String, Value>::deserialize(deserializer)?;
 
-        let (styles, scopes, highlights) = build_theme_values(values);
+        let (styles, scopes, highlights, diagnostics) = build_theme_values(values);
 
         Ok(Self {
             styles,
             scopes,
             highlights,
+            diagnostics,
             ..Default::default()
         })
     }
 }
 
+/// A single recoverable problem encountered while parsing a theme (a bad hex
+/// code, an unknown style attribute, ...). Collected instead of being lost to
+/// `log::warn!` so that a frontend can surface it to the user; see
+/// `Theme::diagnostics`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeDiagnostic {
+    /// The scope the offending entry was defined under, e.g. `"keyword"`, or
+    /// `"palette"` for a malformed palette entry.
+    pub scope: String,
+    /// The offending key or value, e.g. `"#gg0000"`.
+    pub value: String,
+    /// A human-readable description of what went wrong.
+    pub message: String,
+}
+
+impl ThemeDiagnostic {
+    fn new(scope: impl Into<String>, value: impl fmt::Display, message: impl Into<String>) -> Self {
+        Self {
+            scope: scope.into(),
+            value: value.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ThemeDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} `{}` in scope `{}`",
+            self.message, self.value, self.scope
+        )
+    }
+}
+
+/// A human-readable representation of a TOML value for use in diagnostics:
+/// bare text for strings, TOML syntax otherwise.
+fn value_repr(value: &Value) -> String {
+    value
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| value.to_string())
+}
+
 fn build_theme_values(
     mut values: Map<String, Value>,
-) -> (HashMap<String, Style>, Vec<String>, Vec<Style>) {
+) -> (
+    HashMap<String, Style>,
+    Vec<String>,
+    Vec<Style>,
+    Vec<ThemeDiagnostic>,
+) {
     let mut styles = HashMap::new();
     let mut scopes = Vec::new();
     let mut highlights = Vec::new();
+    let mut diagnostics = Vec::new();
 
-    // TODO: alert user of parsing failures in editor
     let palette = values
         .remove("palette")
-        .map(|value| {
-            ThemePalette::try_from(value).unwrap_or_else(|err| {
-                warn!("{}", err);
-                ThemePalette::default()
-            })
-        })
+        .map(|value| ThemePalette::from_toml(value, &mut diagnostics))
         .unwrap_or_default();
     // remove inherits from value to prevent errors
     let _ = values.remove("inherits");
+
+    // Keep the raw, unresolved values around so that a scope's `link` can be
+    // followed to another scope regardless of definition order.
+    let raw_values: HashMap<String, Value> = values
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+
     styles.reserve(values.len());
     scopes.reserve(values.len());
     highlights.reserve(values.len());
-    for (name, style_value) in values {
-        let mut style = Style::default();
-        if let Err(err) = palette.parse_style(&mut style, style_value) {
-            warn!("{}", err);
-        }
+    for name in values.keys() {
+        let mut visiting = HashSet::new();
+        let style = resolve_scope(
+            name,
+            name,
+            &raw_values,
+            &palette,
+            &mut visiting,
+            &mut diagnostics,
+        );
 
         // these are used both as UI and as highlights
         styles.insert(name.clone(), style);
-        scopes.push(name);
+        scopes.push(name.clone());
         highlights.push(style);
     }
 
-    (styles, scopes, highlights)
+    (styles, scopes, highlights, diagnostics)
+}
+
+/// Resolves the style for `name`, following a `link = "other.scope"` table or
+/// bare `"$other.scope"` string to the linked scope's own (possibly linked)
+/// value. Like `Theme::try_get`, a link target that isn't defined directly
+/// falls back to its dot-separated parent scopes before giving up. `visiting`
+/// tracks the keys already followed in this chain so that a cycle (`a` links
+/// to `b` links back to `a`) is detected rather than recursing forever.
+/// `referrer` is the scope a failing link should be blamed on in diagnostics.
+fn resolve_scope<'a>(
+    referrer: &str,
+    name: &str,
+    raw_values: &'a HashMap<String, Value>,
+    palette: &ThemePalette,
+    visiting: &mut HashSet<&'a str>,
+    diagnostics: &mut Vec<ThemeDiagnostic>,
+) -> Style {
+    let resolved = std::iter::successors(Some(name), |s| Some(s.rsplit_once('.')?.0))
+        .find_map(|s| raw_values.get_key_value(s));
+
+    match resolved {
+        Some((key, value)) => resolve_value(key, value, raw_values, palette, visiting, diagnostics),
+        None => {
+            warn!("Theme: link target `{}` does not exist", name);
+            diagnostics.push(ThemeDiagnostic::new(
+                referrer,
+                name,
+                "link target does not exist",
+            ));
+            Style::default()
+        }
+    }
+}
+
+fn resolve_value<'a>(
+    scope: &str,
+    style_value: &'a Value,
+    raw_values: &'a HashMap<String, Value>,
+    palette: &ThemePalette,
+    visiting: &mut HashSet<&'a str>,
+    diagnostics: &mut Vec<ThemeDiagnostic>,
+) -> Style {
+    let link = match style_value {
+        Value::String(s) => s.strip_prefix('$'),
+        Value::Table(table) => match table.get("link") {
+            Some(Value::String(link)) => Some(link.as_str()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if let Some(link) = link {
+        return follow_link(scope, link, raw_values, palette, visiting, diagnostics);
+    }
+
+    let mut style = Style::default();
+    palette.parse_style(&mut style, style_value.clone(), scope, diagnostics);
+    style
+}
+
+fn follow_link<'a>(
+    referrer: &str,
+    link: &'a str,
+    raw_values: &'a HashMap<String, Value>,
+    palette: &ThemePalette,
+    visiting: &mut HashSet<&'a str>,
+    diagnostics: &mut Vec<ThemeDiagnostic>,
+) -> Style {
+    if !visiting.insert(link) {
+        warn!("Theme: cyclic link detected while resolving `{}`", link);
+        diagnostics.push(ThemeDiagnostic::new(referrer, link, "cyclic link"));
+        return Style::default();
+    }
+
+    let style = resolve_scope(referrer, link, raw_values, palette, visiting, diagnostics);
+    visiting.remove(link);
+    style
 }
 
 impl Theme {
@@ -227,6 +422,14 @@ impl Theme {
         &self.name
     }
 
+    /// The background (light/dark) this theme was loaded for: the appearance
+    /// passed to [`Loader::load_with_appearance`], even for themes without a
+    /// `[variants]` table. Themes from [`Loader::load`] and the built-in
+    /// default themes report [`Appearance::Dark`].
+    pub fn appearance(&self) -> Appearance {
+        self.appearance
+    }
+
     pub fn get(&self, scope: &str) -> Style {
         self.try_get(scope).unwrap_or_default()
     }
@@ -255,6 +458,14 @@ impl Theme {
         self.scopes().iter().position(|s| s == scope)
     }
 
+    /// Problems encountered while parsing this theme (malformed hex codes,
+    /// unknown style attributes, ...). Loading still succeeds with
+    /// best-effort styles when this is non-empty; a frontend can use it to
+    /// surface the underlying issues to the user.
+    pub fn diagnostics(&self) -> &[ThemeDiagnostic] {
+        &self.diagnostics
+    }
+
     pub fn is_16_color(&self) -> bool {
         self.styles.iter().all(|(_, style)| {
             [style.fg, style.bg]
@@ -314,13 +525,13 @@ impl ThemePalette {
             }
         }
 
-        Err(format!("Theme: malformed hexcode: {}", s))
+        Err("malformed hexcode".to_string())
     }
 
     fn parse_value_as_str(value: &Value) -> Result<&str, String> {
         value
             .as_str()
-            .ok_or(format!("Theme: unrecognized value: {}", value))
+            .ok_or_else(|| "unrecognized value".to_string())
     }
 
     pub fn parse_color(&self, value: Value) -> Result<Color, String> {
@@ -337,41 +548,94 @@ impl ThemePalette {
         value
             .as_str()
             .and_then(|s| s.parse().ok())
-            .ok_or(format!("Theme: invalid modifier: {}", value))
+            .ok_or_else(|| "invalid modifier".to_string())
     }
 
     pub fn parse_underline_style(value: &Value) -> Result<UnderlineStyle, String> {
         value
             .as_str()
             .and_then(|s| s.parse().ok())
-            .ok_or(format!("Theme: invalid underline style: {}", value))
-    }
-
-    pub fn parse_style(&self, style: &mut Style, value: Value) -> Result<(), String> {
+            .ok_or_else(|| "invalid underline style".to_string())
+    }
+
+    /// Parses `value` into `style`, best-effort: a malformed attribute is
+    /// recorded as a [`ThemeDiagnostic`] (tagged with `scope`) and skipped
+    /// rather than aborting the whole style.
+    pub fn parse_style(
+        &self,
+        style: &mut Style,
+        value: Value,
+        scope: &str,
+        diagnostics: &mut Vec<ThemeDiagnostic>,
+    ) {
         if let Value::Table(entries) = value {
             for (name, mut value) in entries {
                 match name.as_str() {
-                    "fg" => *style = style.fg(self.parse_color(value)?),
-                    "bg" => *style = style.bg(self.parse_color(value)?),
+                    "fg" => {
+                        let repr = value_repr(&value);
+                        match self.parse_color(value) {
+                            Ok(color) => *style = style.fg(color),
+                            Err(message) => {
+                                diagnostics.push(ThemeDiagnostic::new(scope, repr, message))
+                            }
+                        }
+                    }
+                    "bg" => {
+                        let repr = value_repr(&value);
+                        match self.parse_color(value) {
+                            Ok(color) => *style = style.bg(color),
+                            Err(message) => {
+                                diagnostics.push(ThemeDiagnostic::new(scope, repr, message))
+                            }
+                        }
+                    }
                     "underline" => {
-                        let table = value
-                            .as_table_mut()
-                            .ok_or("Theme: underline must be table")?;
+                        let Some(table) = value.as_table_mut() else {
+                            diagnostics.push(ThemeDiagnostic::new(
+                                scope,
+                                value_repr(&value),
+                                "underline must be a table",
+                            ));
+                            continue;
+                        };
                         if let Some(value) = table.remove("color") {
-                            *style = style.underline_color(self.parse_color(value)?);
+                            let repr = value_repr(&value);
+                            match self.parse_color(value) {
+                                Ok(color) => *style = style.underline_color(color),
+                                Err(message) => {
+                                    diagnostics.push(ThemeDiagnostic::new(scope, repr, message))
+                                }
+                            }
                         }
                         if let Some(value) = table.remove("style") {
-                            *style = style.underline_style(Self::parse_underline_style(&value)?);
+                            let repr = value_repr(&value);
+                            match Self::parse_underline_style(&value) {
+                                Ok(underline_style) => {
+                                    *style = style.underline_style(underline_style)
+                                }
+                                Err(message) => {
+                                    diagnostics.push(ThemeDiagnostic::new(scope, repr, message))
+                                }
+                            }
                         }
 
                         if let Some(attr) = table.keys().next() {
-                            return Err(format!("Theme: invalid underline attribute: {attr}"));
+                            diagnostics.push(ThemeDiagnostic::new(
+                                scope,
+                                attr,
+                                "invalid underline attribute",
+                            ));
                         }
                     }
                     "modifiers" => {
-                        let modifiers = value
-                            .as_array()
-                            .ok_or("Theme: modifiers should be an array")?;
+                        let Some(modifiers) = value.as_array() else {
+                            diagnostics.push(ThemeDiagnostic::new(
+                                scope,
+                                value_repr(&value),
+                                "modifiers should be an array",
+                            ));
+                            continue;
+                        };
 
                         for modifier in modifiers {
                             if modifier
@@ -380,37 +644,57 @@ impl ThemePalette {
                             {
                                 *style = style.underline_style(UnderlineStyle::Line);
                             } else {
-                                *style = style.add_modifier(Self::parse_modifier(modifier)?);
+                                match Self::parse_modifier(modifier) {
+                                    Ok(modifier) => *style = style.add_modifier(modifier),
+                                    Err(message) => diagnostics.push(ThemeDiagnostic::new(
+                                        scope,
+                                        value_repr(modifier),
+                                        message,
+                                    )),
+                                }
                             }
                         }
                     }
-                    _ => return Err(format!("Theme: invalid style attribute: {}", name)),
+                    _ => diagnostics.push(ThemeDiagnostic::new(
+                        scope,
+                        name,
+                        "invalid style attribute",
+                    )),
                 }
             }
         } else {
-            *style = style.fg(self.parse_color(value)?);
+            let repr = value_repr(&value);
+            match self.parse_color(value) {
+                Ok(color) => *style = style.fg(color),
+                Err(message) => diagnostics.push(ThemeDiagnostic::new(scope, repr, message)),
+            }
         }
-        Ok(())
     }
 }
 
-impl TryFrom<Value> for ThemePalette {
-    type Error = String;
-
-    fn try_from(value: Value) -> Result<Self, Self::Error> {
+impl ThemePalette {
+    /// Parses a `[palette]` table, best-effort: a malformed entry is recorded
+    /// as a [`ThemeDiagnostic`] and skipped rather than discarding the whole
+    /// palette.
+    pub fn from_toml(value: Value, diagnostics: &mut Vec<ThemeDiagnostic>) -> Self {
         let map = match value {
             Value::Table(entries) => entries,
-            _ => return Ok(Self::default()),
+            _ => return Self::default(),
         };
 
         let mut palette = HashMap::with_capacity(map.len());
         for (name, value) in map {
-            let value = Self::parse_value_as_str(&value)?;
-            let color = Self::hex_string_to_rgb(value)?;
-            palette.insert(name, color);
+            let repr = value_repr(&value);
+            let color = Self::parse_value_as_str(&value).and_then(Self::hex_string_to_rgb);
+            match color {
+                Ok(color) => {
+                    palette.insert(name, color);
+                }
+                Err(message) => diagnostics.push(ThemeDiagnostic::new("palette", repr, message)),
+            }
         }
 
-        Ok(Self::new(palette))
+        Self::new(palette)
     }
 }
 
@@ -423,10 +707,12 @@ mod tests {
         let fg = Value::String("#ffffff".to_string());
 
         let mut style = Style::default();
+        let mut diagnostics = Vec::new();
         let palette = ThemePalette::default();
-        palette.parse_style(&mut style, fg).unwrap();
+        palette.parse_style(&mut style, fg, "keyword", &mut diagnostics);
 
         assert_eq!(style, Style::default().fg(Color::Rgb(255, 255, 255)));
+        assert!(diagnostics.is_empty());
     }
 
     #[test]
@@ -435,11 +721,13 @@ mod tests {
         let fg = Value::String("my_color".to_string());
 
         let mut style = Style::default();
+        let mut diagnostics = Vec::new();
         let palette =
             ThemePalette::new(hashmap! { "my_color".to_string() => Color::Rgb(255, 255, 255) });
-        palette.parse_style(&mut style, fg).unwrap();
+        palette.parse_style(&mut style, fg, "keyword", &mut diagnostics);
 
         assert_eq!(style, Style::default().fg(Color::Rgb(255, 255, 255)));
+        assert!(diagnostics.is_empty());
     }
 
     #[test]
@@ -453,9 +741,10 @@ mod tests {
         };
 
         let mut style = Style::default();
+        let mut diagnostics = Vec::new();
         let palette = ThemePalette::default();
-        for (_name, value) in table {
-            palette.parse_style(&mut style, value).unwrap();
+        for (name, value) in table {
+            palette.parse_style(&mut style, value, &name, &mut diagnostics);
         }
 
         assert_eq!(
@@ -465,5 +754,147 @@ mod tests {
                 .bg(Color::Rgb(0, 0, 0))
                 .add_modifier(Modifier::BOLD)
         );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_parse_style_table_collects_diagnostics_and_keeps_valid_attrs() {
+        let table = toml::toml! {
+            "keyword" = {
+                fg = "#gg0000",
+                bg = "#000000",
+                wat = "unused",
+            }
+        };
+
+        let mut style = Style::default();
+        let mut diagnostics = Vec::new();
+        let palette = ThemePalette::default();
+        for (name, value) in table {
+            palette.parse_style(&mut style, value, &name, &mut diagnostics);
+        }
+
+        // the bad `fg` and unknown `wat` attribute are skipped, `bg` is kept
+        assert_eq!(style, Style::default().bg(Color::Rgb(0, 0, 0)));
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().any(|d| d.scope == "keyword"
+            && d.value == "#gg0000"
+            && d.message == "malformed hexcode"));
+        assert!(diagnostics.iter().any(|d| d.scope == "keyword"
+            && d.value == "wat"
+            && d.message == "invalid style attribute"));
+    }
+
+    #[test]
+    fn test_theme_palette_from_toml_collects_diagnostics_and_keeps_valid_colors() {
+        let table = toml::toml! {
+            good = "#ffffff"
+            bad = "not-a-color"
+        };
+
+        let mut diagnostics = Vec::new();
+        let palette = ThemePalette::from_toml(table, &mut diagnostics);
+
+        assert_eq!(
+            palette.parse_color(Value::String("good".to_string())),
+            Ok(Color::Rgb(255, 255, 255))
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].scope, "palette");
+        assert_eq!(diagnostics[0].message, "malformed hexcode");
+    }
+
+    #[test]
+    fn test_link_table_and_sigil() {
+        let table = toml::toml! {
+            "ui.text" = { fg = "#ffffff" }
+            "ui.text.focus" = { link = "ui.text" }
+            "ui.text.focus.sigil" = "$ui.text.focus"
+        };
+
+        let (styles, ..) = build_theme_values(table);
+
+        let expected = Style::default().fg(Color::Rgb(255, 255, 255));
+        assert_eq!(styles["ui.text.focus"], expected);
+        assert_eq!(styles["ui.text.focus.sigil"], expected);
+    }
+
+    #[test]
+    fn test_link_falls_back_to_dot_separated_scope() {
+        let table = toml::toml! {
+            "ui" = { fg = "#ffffff" }
+            "ui.text.focus" = { link = "ui.text" }
+        };
+
+        let (styles, ..) = build_theme_values(table);
+
+        // `ui.text` isn't defined, so the link falls back to `ui` the same
+        // way `Theme::try_get` would.
+        assert_eq!(
+            styles["ui.text.focus"],
+            Style::default().fg(Color::Rgb(255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn test_link_cycle_falls_back_to_default() {
+        let table = toml::toml! {
+            "a" = { link = "b" }
+            "b" = { link = "a" }
+        };
+
+        let (styles, ..) = build_theme_values(table);
+
+        assert_eq!(styles["a"], Style::default());
+        assert_eq!(styles["b"], Style::default());
+    }
+
+    #[test]
+    fn test_select_variant_merges_shared_base() {
+        let loader = Loader::new("/does/not/exist", "/does/not/exist");
+
+        let theme_toml = toml::toml! {
+            "ui.text" = { fg = "#ffffff" }
+            "ui.text.focus" = { fg = "#ffffff" }
+            variants = { light = { "ui.text" = { fg = "#000000" } } }
+        };
+
+        let merged = loader.select_variant(theme_toml, Appearance::Light);
+        let theme = Theme::from(merged);
+
+        // overridden by the light variant
+        assert_eq!(
+            theme.get("ui.text"),
+            Style::default().fg(Color::Rgb(0, 0, 0))
+        );
+        // inherited unchanged from the shared base
+        assert_eq!(
+            theme.get("ui.text.focus"),
+            Style::default().fg(Color::Rgb(255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn test_select_variant_without_variants_table_is_unchanged() {
+        let loader = Loader::new("/does/not/exist", "/does/not/exist");
+
+        let theme_toml = toml::toml! {
+            "ui.text" = { fg = "#ffffff" }
+        };
+
+        let merged = loader.select_variant(theme_toml.clone(), Appearance::Light);
+        assert_eq!(merged, theme_toml);
+    }
+
+    #[test]
+    fn test_link_missing_target_falls_back_to_default() {
+        let table = toml::toml! {
+            "a" = { link = "does-not-exist" }
+        };
+
+        let (styles, ..) = build_theme_values(table);
+
+        assert_eq!(styles["a"], Style::default());
     }
 }